@@ -0,0 +1 @@
+mod timing_wheel;
@@ -1,42 +1,99 @@
 use std::{
     self,
+    cell::{Cell, RefCell},
     ffi::c_void,
+    mem::ManuallyDrop,
+    os::unix::io::RawFd,
     panic::{AssertUnwindSafe, UnwindSafe},
     ptr,
     rc::Weak,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
-use core_foundation::base::{CFIndex, CFOptionFlags, CFRelease};
+use core_foundation::base::{CFIndex, CFOptionFlags, CFRelease, TCFType};
 use core_foundation::date::CFAbsoluteTimeGetCurrent;
 use core_foundation::runloop::{
     kCFRunLoopAfterWaiting, kCFRunLoopBeforeWaiting, kCFRunLoopCommonModes, kCFRunLoopExit,
-    CFRunLoopActivity, CFRunLoopAddObserver, CFRunLoopAddTimer, CFRunLoopGetMain,
-    CFRunLoopObserverCallBack, CFRunLoopObserverContext, CFRunLoopObserverCreate,
-    CFRunLoopObserverRef, CFRunLoopRef, CFRunLoopTimerCreate, CFRunLoopTimerInvalidate,
-    CFRunLoopTimerRef, CFRunLoopTimerSetNextFireDate, CFRunLoopWakeUp,
+    CFRunLoop, CFRunLoopActivity, CFRunLoopAddObserver, CFRunLoopAddSource, CFRunLoopAddTimer,
+    CFRunLoopCopyCurrentMode, CFRunLoopGetMain, CFRunLoopObserverCallBack,
+    CFRunLoopObserverContext, CFRunLoopObserverCreate, CFRunLoopObserverRef, CFRunLoopSourceRef,
+    CFRunLoopTimerCreate, CFRunLoopTimerInvalidate, CFRunLoopTimerRef,
+    CFRunLoopTimerSetNextFireDate, CFRunLoopTimerSetTolerance, CFRunLoopWakeUp,
 };
+use core_foundation::string::CFString;
 use icrate::Foundation::MainThreadMarker;
 
 use super::ffi;
+use super::timing_wheel::{TimerKey, TimingWheel};
 use super::{
     app_delegate::ApplicationDelegate,
-    event_loop::{stop_app_on_panic, PanicInfo},
+    event_loop::{stop_app_on_panic, EventLoop, PanicInfo},
 };
 
+thread_local! {
+    /// Tracks whether the `EventLoopWaker` is currently in `ControlFlow::Poll`,
+    /// i.e. `control_flow_end_handler` wakes the loop back up immediately
+    /// instead of letting it sleep.
+    static POLLING: Cell<bool> = Cell::new(false);
+
+    /// Every `EventLoopWaker` timer on this thread that needs to keep firing
+    /// no matter which run-loop mode AppKit switches to.
+    static MODE_AWARE_TIMERS: RefCell<Vec<CFRunLoopTimerRef>> = RefCell::new(Vec::new());
+
+    /// Every mode `ensure_run_loop_mode_registered` has already added the
+    /// `MODE_AWARE_TIMERS` to, so it isn't redone on every iteration.
+    static SEEN_RUN_LOOP_MODES: RefCell<Vec<CFString>> = RefCell::new(Vec::new());
+}
+
+/// Registers `timer` to be kept alive in every run-loop mode observed from
+/// now on (see `ensure_run_loop_mode_registered`), including modes already
+/// seen on this thread.
+fn register_mode_aware_timer(timer: CFRunLoopTimerRef) {
+    SEEN_RUN_LOOP_MODES.with(|modes| {
+        for mode in modes.borrow().iter() {
+            unsafe { CFRunLoopAddTimer(CFRunLoopGetMain(), timer, mode.as_concrete_TypeRef()) };
+        }
+    });
+    MODE_AWARE_TIMERS.with(|timers| timers.borrow_mut().push(timer));
+}
+
+/// Reverses `register_mode_aware_timer`; called from `EventLoopWaker::drop`.
+fn unregister_mode_aware_timer(timer: CFRunLoopTimerRef) {
+    MODE_AWARE_TIMERS.with(|timers| timers.borrow_mut().retain(|&t| t != timer));
+}
+
+/// Ensures every mode-aware timer stays registered in `mode`. AppKit switches
+/// the main run loop into private tracking modes (live window resize,
+/// menu/scrollbar tracking) that aren't part of `kCFRunLoopCommonModes`, so
+/// without this `WaitUntil` deadlines and redraws would stop being serviced
+/// mid-resize. Called from `control_flow_begin_handler` whenever the current
+/// mode changes.
+fn ensure_run_loop_mode_registered(mode: &CFString) {
+    let already_seen = SEEN_RUN_LOOP_MODES.with(|modes| modes.borrow().contains(mode));
+    if already_seen {
+        return;
+    }
+    MODE_AWARE_TIMERS.with(|timers| {
+        for &timer in timers.borrow().iter() {
+            unsafe { CFRunLoopAddTimer(CFRunLoopGetMain(), timer, mode.as_concrete_TypeRef()) };
+        }
+    });
+    SEEN_RUN_LOOP_MODES.with(|modes| modes.borrow_mut().push(mode.clone()));
+}
+
 unsafe fn control_flow_handler<F>(panic_info: *mut c_void, f: F)
 where
     F: FnOnce(Weak<PanicInfo>) + UnwindSafe,
 {
-    let info_from_raw = unsafe { Weak::from_raw(panic_info as *mut PanicInfo) };
+    // The observer context now owns `panic_info` through its `retain`/
+    // `release` callbacks (see `context_retain`/`context_release` below), so
+    // this pointer is only ever *borrowed* for the duration of the callback.
+    // `ManuallyDrop` lets us reconstruct the `Weak` to clone it without
+    // running its destructor and dropping the observer's own reference.
+    let info_from_raw = ManuallyDrop::new(unsafe { Weak::from_raw(panic_info as *mut PanicInfo) });
     // Asserting unwind safety on this type should be fine because `PanicInfo` is
     // `RefUnwindSafe` and `Rc<T>` is `UnwindSafe` if `T` is `RefUnwindSafe`.
     let panic_info = AssertUnwindSafe(Weak::clone(&info_from_raw));
-    // `from_raw` takes ownership of the data behind the pointer.
-    // But if this scope takes ownership of the weak pointer, then
-    // the weak pointer will get free'd at the end of the scope.
-    // However we want to keep that weak reference around after the function.
-    std::mem::forget(info_from_raw);
 
     let mtm = MainThreadMarker::new().unwrap();
     stop_app_on_panic(mtm, Weak::clone(&panic_info), move || {
@@ -57,6 +114,13 @@ extern "C" fn control_flow_begin_handler(
             match activity {
                 kCFRunLoopAfterWaiting => {
                     //trace!("Triggered `CFRunLoopAfterWaiting`");
+                    // Make sure every mode-aware timer stays registered in
+                    // whatever mode AppKit just switched to, so deadlines and
+                    // redraws aren't starved by e.g. live resize or menu
+                    // tracking.
+                    if let Some(mode) = RunLoop::get().current_mode() {
+                        ensure_run_loop_mode_registered(&mode);
+                    }
                     ApplicationDelegate::get(MainThreadMarker::new().unwrap()).wakeup(panic_info);
                     //trace!("Completed `CFRunLoopAfterWaiting`");
                 }
@@ -80,6 +144,12 @@ extern "C" fn control_flow_end_handler(
                 kCFRunLoopBeforeWaiting => {
                     //trace!("Triggered `CFRunLoopBeforeWaiting`");
                     ApplicationDelegate::get(MainThreadMarker::new().unwrap()).cleared(panic_info);
+                    // In `ControlFlow::Poll` there's no fire date to wait on, so
+                    // nudge the run loop to go straight back around instead of
+                    // actually blocking in `kCFRunLoopBeforeWaiting`.
+                    if POLLING.with(Cell::get) {
+                        RunLoop::get().wakeup();
+                    }
                     //trace!("Completed `CFRunLoopBeforeWaiting`");
                 }
                 kCFRunLoopExit => (), //unimplemented!(), // not expected to ever happen
@@ -89,15 +159,31 @@ extern "C" fn control_flow_end_handler(
     }
 }
 
-pub struct RunLoop(CFRunLoopRef);
+/// A safe, retain-counted handle to the main `CFRunLoop`, as opposed to a bare
+/// `CFRunLoopRef` with no ownership semantics.
+pub struct RunLoop(CFRunLoop);
 
 impl RunLoop {
     pub unsafe fn get() -> Self {
-        RunLoop(unsafe { CFRunLoopGetMain() })
+        RunLoop(unsafe { CFRunLoop::wrap_under_get_rule(CFRunLoopGetMain()) })
     }
 
     pub fn wakeup(&self) {
-        unsafe { CFRunLoopWakeUp(self.0) }
+        unsafe { CFRunLoopWakeUp(self.0.as_concrete_TypeRef()) }
+    }
+
+    /// The mode the run loop is currently running in, e.g. the private
+    /// tracking modes AppKit switches into during live window resize or
+    /// menu/scrollbar tracking, as opposed to `kCFRunLoopDefaultMode`.
+    pub fn current_mode(&self) -> Option<CFString> {
+        unsafe {
+            let mode = CFRunLoopCopyCurrentMode(self.0.as_concrete_TypeRef());
+            if mode.is_null() {
+                None
+            } else {
+                Some(CFString::wrap_under_create_rule(mode))
+            }
+        }
     }
 
     unsafe fn add_observer(
@@ -117,17 +203,173 @@ impl RunLoop {
                 context,
             )
         };
-        unsafe { CFRunLoopAddObserver(self.0, observer, kCFRunLoopCommonModes) };
+        unsafe {
+            CFRunLoopAddObserver(self.0.as_concrete_TypeRef(), observer, kCFRunLoopCommonModes)
+        };
+    }
+
+    /// Registers `fd` as a wakeup source for the main run loop: `callback`
+    /// is invoked on the main thread whenever `fd` becomes ready for the
+    /// given `interest` (a combination of `kCFFileDescriptorReadCallBack` and
+    /// `kCFFileDescriptorWriteCallBack`). This lets callers integrate their
+    /// own async I/O into the event loop directly, instead of spawning a
+    /// helper thread that wakes winit up through an `EventLoopProxy`.
+    ///
+    /// The returned [`FdHandle`] must be kept alive for as long as the
+    /// wakeup source should stay registered; dropping it invalidates the
+    /// underlying `CFFileDescriptor`.
+    pub unsafe fn register_fd(
+        &self,
+        fd: RawFd,
+        interest: CFOptionFlags,
+        callback: impl FnMut(RawFd) + 'static,
+    ) -> FdHandle {
+        let info = Box::into_raw(Box::new(FdCallback(Box::new(callback))));
+        let context = CFFileDescriptorContext {
+            version: 0,
+            info: info as *mut c_void,
+            retain: None,
+            release: None,
+            copy_description: None,
+        };
+        unsafe {
+            let cf_fd = CFFileDescriptorCreate(
+                ptr::null_mut(),
+                fd as CFIndex,
+                ffi::FALSE, // closeOnInvalidate: `fd` is borrowed, not owned
+                fd_callback_trampoline,
+                &context,
+            );
+            CFFileDescriptorEnableCallBacks(cf_fd, interest);
+            let source = CFFileDescriptorCreateRunLoopSource(ptr::null_mut(), cf_fd, 0);
+            CFRunLoopAddSource(self.0.as_concrete_TypeRef(), source, kCFRunLoopCommonModes);
+            FdHandle {
+                fd: cf_fd,
+                source,
+                _callback: Box::from_raw(info),
+            }
+        }
+    }
+}
+
+impl EventLoop {
+    /// Sets the tolerance Core Foundation is allowed when firing this event
+    /// loop's `WaitUntil` deadlines, in exchange for batching this wakeup
+    /// with other scheduled work; see [`EventLoopWaker::set_tolerance`].
+    pub fn set_waker_tolerance(&mut self, tolerance: Duration) {
+        self.waker.set_tolerance(tolerance);
+    }
+
+    /// Registers `fd` as a wakeup source on this event loop's run loop; see
+    /// [`RunLoop::register_fd`] for what `interest` and `callback` mean and
+    /// how the returned [`FdHandle`] must be kept alive. This is the
+    /// platform-specific entry point callers outside this module reach the
+    /// feature through, since `RunLoop` itself is not exposed outside
+    /// `platform_impl`.
+    pub unsafe fn register_fd(
+        &self,
+        fd: RawFd,
+        interest: CFOptionFlags,
+        callback: impl FnMut(RawFd) + 'static,
+    ) -> FdHandle {
+        unsafe { RunLoop::get().register_fd(fd, interest, callback) }
+    }
+}
+
+type CFFileDescriptorRef = *mut c_void;
+
+#[repr(C)]
+struct CFFileDescriptorContext {
+    version: CFIndex,
+    info: *mut c_void,
+    retain: Option<extern "C" fn(*const c_void) -> *const c_void>,
+    release: Option<extern "C" fn(*const c_void)>,
+    copy_description: Option<extern "C" fn(*const c_void) -> *mut c_void>,
+}
+
+#[allow(non_upper_case_globals)]
+pub const kCFFileDescriptorReadCallBack: CFOptionFlags = 1 << 0;
+#[allow(non_upper_case_globals)]
+pub const kCFFileDescriptorWriteCallBack: CFOptionFlags = 1 << 1;
+
+extern "C" {
+    fn CFFileDescriptorCreate(
+        allocator: *mut c_void,
+        fd: CFIndex,
+        close_on_invalidate: u8,
+        call_out: extern "C" fn(CFFileDescriptorRef, CFOptionFlags, *mut c_void),
+        context: *const CFFileDescriptorContext,
+    ) -> CFFileDescriptorRef;
+    fn CFFileDescriptorGetNativeDescriptor(f: CFFileDescriptorRef) -> CFIndex;
+    fn CFFileDescriptorEnableCallBacks(f: CFFileDescriptorRef, call_back_types: CFOptionFlags);
+    fn CFFileDescriptorInvalidate(f: CFFileDescriptorRef);
+    fn CFFileDescriptorCreateRunLoopSource(
+        allocator: *mut c_void,
+        f: CFFileDescriptorRef,
+        order: CFIndex,
+    ) -> CFRunLoopSourceRef;
+}
+
+/// The boxed closure handed to a [`FdHandle`]'s `CFFileDescriptorContext`.
+struct FdCallback(Box<dyn FnMut(RawFd)>);
+
+extern "C" fn fd_callback_trampoline(
+    f: CFFileDescriptorRef,
+    call_back_types: CFOptionFlags,
+    info: *mut c_void,
+) {
+    unsafe {
+        let callback = &mut *(info as *mut FdCallback);
+        (callback.0)(CFFileDescriptorGetNativeDescriptor(f) as RawFd);
+        // Core Foundation disables the callback type(s) that just fired;
+        // re-enable them so future readiness keeps waking us up.
+        CFFileDescriptorEnableCallBacks(f, call_back_types);
+    }
+}
+
+/// A run-loop wakeup source registered via [`RunLoop::register_fd`]. Dropping
+/// this invalidates the `CFFileDescriptor`, mirroring how `EventLoopWaker`'s
+/// `Drop` invalidates its timer.
+pub struct FdHandle {
+    fd: CFFileDescriptorRef,
+    source: CFRunLoopSourceRef,
+    _callback: Box<FdCallback>,
+}
+
+impl Drop for FdHandle {
+    fn drop(&mut self) {
+        unsafe {
+            CFFileDescriptorInvalidate(self.fd);
+            CFRelease(self.source as _);
+            CFRelease(self.fd as _);
+        }
+    }
+}
+
+/// `CFRunLoopObserverContext::retain`: called by Core Foundation when an
+/// observer is created to obtain its own owned reference to `info`, so each
+/// observer's lifetime is independent of the others'.
+extern "C" fn context_retain(info: *const c_void) -> *const c_void {
+    unsafe {
+        let weak = ManuallyDrop::new(Weak::from_raw(info as *const PanicInfo));
+        Weak::into_raw(Weak::clone(&weak)) as *const c_void
     }
 }
 
+/// `CFRunLoopObserverContext::release`: called by Core Foundation when an
+/// observer holding this `info` is invalidated, reclaiming the `Weak` that
+/// `context_retain` (or the initial construction below) handed it.
+extern "C" fn context_release(info: *const c_void) {
+    unsafe { drop(Weak::from_raw(info as *const PanicInfo)) }
+}
+
 pub fn setup_control_flow_observers(panic_info: Weak<PanicInfo>) {
     unsafe {
         let mut context = CFRunLoopObserverContext {
             info: Weak::into_raw(panic_info) as *mut _,
             version: 0,
-            retain: None,
-            release: None,
+            retain: Some(context_retain),
+            release: Some(context_release),
             copyDescription: None,
         };
         let run_loop = RunLoop::get();
@@ -143,6 +385,10 @@ pub fn setup_control_flow_observers(panic_info: Weak<PanicInfo>) {
             control_flow_end_handler,
             &mut context as *mut _,
         );
+        // `CFRunLoopObserverCreate` called `context_retain` for each observer
+        // above to obtain its own reference; reclaim the template reference
+        // we created for `context.info` itself so it isn't leaked.
+        context_release(context.info as *const c_void);
     }
 }
 
@@ -159,10 +405,34 @@ pub struct EventLoopWaker {
     /// `None` corresponds to `waker.stop()` and `start_instant` is used
     /// for `waker.start()`
     next_fire_date: Option<Instant>,
+
+    /// The tolerance applied to `next_fire_date` whenever a `WaitUntil`
+    /// deadline is programmed, letting Core Foundation coalesce this timer's
+    /// firing with other scheduled work. Kept here so it survives
+    /// reprogramming the timer. Defaults to zero, i.e. the precise behavior
+    /// `winit` has always had.
+    tolerance: Duration,
+
+    /// The deadline most recently requested through `stop`/`start`/`start_at`,
+    /// i.e. what `ControlFlow` itself asked for.
+    cf_deadline: CfDeadline,
+
+    /// Keyed deadlines registered through `schedule`/`cancel`, independent of
+    /// `ControlFlow`. The timer is always programmed for whichever of
+    /// `cf_deadline` and the wheel's earliest entry comes first.
+    wheel: TimingWheel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CfDeadline {
+    Stopped,
+    Poll,
+    WaitUntil(Instant),
 }
 
 impl Drop for EventLoopWaker {
     fn drop(&mut self) {
+        unregister_mode_aware_timer(self.timer);
         unsafe {
             CFRunLoopTimerInvalidate(self.timer);
             CFRelease(self.timer as _);
@@ -174,7 +444,15 @@ impl Default for EventLoopWaker {
     fn default() -> EventLoopWaker {
         extern "C" fn wakeup_main_loop(_timer: CFRunLoopTimerRef, _info: *mut c_void) {}
         unsafe {
-            // Create a timer with a 0.1µs interval (1ns does not work) to mimic polling.
+            // While polling, `control_flow_end_handler` also wakes the run loop
+            // up directly on every iteration. The interval below is not 0:
+            // Core Foundation auto-invalidates a timer the instant a strictly
+            // one-shot (interval-0) fire happens, and every later
+            // `CFRunLoopTimerSetNextFireDate` call in `reprogram` would then
+            // silently no-op on the dead timer. With the 0.1µs interval, a
+            // timer armed to fire immediately (in `ControlFlow::Poll`, or for
+            // an already-elapsed deadline) keeps re-firing on every pass of
+            // the run loop until `reprogram` moves its fire date again.
             // It is initially setup with a first fire time really far into the
             // future, but that gets changed to fire immediately in did_finish_launching
             let timer = CFRunLoopTimerCreate(
@@ -187,35 +465,121 @@ impl Default for EventLoopWaker {
                 ptr::null_mut(),
             );
             CFRunLoopAddTimer(CFRunLoopGetMain(), timer, kCFRunLoopCommonModes);
+            register_mode_aware_timer(timer);
+            let start_instant = Instant::now();
             EventLoopWaker {
                 timer,
-                start_instant: Instant::now(),
+                start_instant,
                 next_fire_date: None,
+                tolerance: Duration::ZERO,
+                cf_deadline: CfDeadline::Stopped,
+                wheel: TimingWheel::new(start_instant),
             }
         }
     }
 }
 
 impl EventLoopWaker {
-    pub fn stop(&mut self) {
+    /// Sets the tolerance Core Foundation is allowed when firing future
+    /// `WaitUntil` deadlines, in exchange for batching this timer's wakeup
+    /// with other scheduled work. A `ControlFlow::WaitUntil` may now fire up
+    /// to `tolerance` late. Defaults to zero, which preserves the precise
+    /// firing behavior.
+    pub fn set_tolerance(&mut self, tolerance: Duration) {
+        self.tolerance = tolerance;
         if self.next_fire_date.is_some() {
-            self.next_fire_date = None;
-            unsafe { CFRunLoopTimerSetNextFireDate(self.timer, std::f64::MAX) }
+            unsafe { CFRunLoopTimerSetTolerance(self.timer, tolerance.as_secs_f64()) }
         }
     }
 
+    pub fn stop(&mut self) {
+        self.cf_deadline = CfDeadline::Stopped;
+        self.reprogram();
+    }
+
     pub fn start(&mut self) {
-        if self.next_fire_date != Some(self.start_instant) {
-            self.next_fire_date = Some(self.start_instant);
-            unsafe { CFRunLoopTimerSetNextFireDate(self.timer, std::f64::MIN) }
-        }
+        self.cf_deadline = CfDeadline::Poll;
+        self.reprogram();
     }
 
     pub fn start_at(&mut self, instant: Option<Instant>) {
+        self.cf_deadline = match instant {
+            None => CfDeadline::Stopped,
+            // Deliberately not `CfDeadline::Poll`: an already-elapsed
+            // `WaitUntil` does not make `reprogram` set `POLLING`, so
+            // `control_flow_end_handler` does not wake the loop for it.
+            // `reprogram`'s `WaitUntil` handling below arms the timer to fire
+            // immediately for a deadline that's in the past.
+            Some(instant) => CfDeadline::WaitUntil(instant),
+        };
+        self.reprogram();
+    }
+
+    /// Registers a keyed wakeup for `instant`, independent of `ControlFlow`.
+    /// The timer is reprogrammed immediately if this becomes the earliest
+    /// outstanding deadline.
+    pub fn schedule(&mut self, instant: Instant) -> TimerKey {
+        let key = self.wheel.insert(instant);
+        self.reprogram();
+        key
+    }
+
+    /// Cancels a deadline previously registered with `schedule`. A no-op if
+    /// it already fired.
+    pub fn cancel(&mut self, key: TimerKey) {
+        self.wheel.remove(key);
+        self.reprogram();
+    }
+
+    /// Drains every keyed deadline that is now due. Should be called
+    /// whenever the waker's timer fires, alongside whatever `ControlFlow`
+    /// processing `winit` already does for its own deadline; reprograms the
+    /// timer to the new earliest deadline afterwards.
+    pub fn poll_expired(&mut self, now: Instant) -> std::vec::IntoIter<TimerKey> {
+        let expired: Vec<TimerKey> = self.wheel.poll_expired(now).collect();
+        self.reprogram();
+        expired.into_iter()
+    }
+
+    /// Programs the single `CFRunLoopTimer` for the earlier of `cf_deadline`
+    /// and the timing wheel's earliest entry.
+    fn reprogram(&mut self) {
+        POLLING.with(|polling| polling.set(self.cf_deadline == CfDeadline::Poll));
+
+        if self.cf_deadline == CfDeadline::Poll {
+            // Poll always wins: `start_instant` is in the past, so it's
+            // always at least as early as anything the wheel is tracking.
+            if self.next_fire_date != Some(self.start_instant) {
+                self.next_fire_date = Some(self.start_instant);
+                unsafe { CFRunLoopTimerSetNextFireDate(self.timer, std::f64::MIN) }
+            }
+            return;
+        }
+
+        let cf_instant = match self.cf_deadline {
+            CfDeadline::WaitUntil(instant) => Some(instant),
+            CfDeadline::Stopped | CfDeadline::Poll => None,
+        };
+        let target = match (cf_instant, self.wheel.next_deadline()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (Some(a), None) => Some(a),
+            (None, Some(b)) => Some(b),
+            (None, None) => None,
+        };
+
         let now = Instant::now();
-        match instant {
-            Some(instant) if now >= instant => {
-                self.start();
+        match target {
+            None => {
+                if self.next_fire_date.is_some() {
+                    self.next_fire_date = None;
+                    unsafe { CFRunLoopTimerSetNextFireDate(self.timer, std::f64::MAX) }
+                }
+            }
+            Some(instant) if instant <= now => {
+                if self.next_fire_date != Some(self.start_instant) {
+                    self.next_fire_date = Some(self.start_instant);
+                    unsafe { CFRunLoopTimerSetNextFireDate(self.timer, std::f64::MIN) }
+                }
             }
             Some(instant) => {
                 if self.next_fire_date != Some(instant) {
@@ -225,13 +589,13 @@ impl EventLoopWaker {
                         let duration = instant - now;
                         let fsecs = duration.subsec_nanos() as f64 / 1_000_000_000.0
                             + duration.as_secs() as f64;
-                        CFRunLoopTimerSetNextFireDate(self.timer, current + fsecs)
+                        CFRunLoopTimerSetNextFireDate(self.timer, current + fsecs);
+                        // Re-apply on every reprogram: Core Foundation does not
+                        // remember the tolerance across `SetNextFireDate` calls.
+                        CFRunLoopTimerSetTolerance(self.timer, self.tolerance.as_secs_f64());
                     }
                 }
             }
-            None => {
-                self.stop();
-            }
         }
     }
 }
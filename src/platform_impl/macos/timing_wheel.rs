@@ -0,0 +1,247 @@
+//! A small hierarchical timing wheel, in the style of Tokio's timer driver,
+//! for tracking many independent [`Instant`] deadlines behind a single
+//! `CFRunLoopTimer`.
+//!
+//! Without this, every subsystem that wants its own timed wakeup (per-window
+//! redraw throttling, user timers delivered to `UserEvent`, animation
+//! callbacks) would have to collapse its deadline into the single
+//! `ControlFlow::WaitUntil` the application hands `winit`, and `winit` would
+//! have to recompute the earliest one by hand. Instead each deadline is
+//! `insert`-ed here under its own [`TimerKey`], and `EventLoopWaker`
+//! reprograms its one `CFRunLoopTimer` from [`TimingWheel::next_deadline`].
+//!
+//! Timers are placed in the slot of the lowest level whose span covers their
+//! remaining delay. `poll_expired` walks the level boundaries crossed since
+//! the last poll directly (see `crossed_boundaries`) rather than stepping
+//! through every elapsed tick, and cascades a crossed slot's
+//! entries straight to their final bucket relative to the new tick rather
+//! than one level at a time, so a deadline landing exactly on a level
+//! boundary is cascaded in time to be drained on the same call rather than
+//! missed. Each slot also keeps its own minimum deadline alongside its
+//! entries, updated on every push/remove/drain, so
+//! [`TimingWheel::next_deadline`] only scans the fixed `LEVELS *
+//! SLOTS_PER_LEVEL` slot minimums rather than every outstanding entry. This
+//! keeps insertion and expiry O(1) amortized, independent of both the number
+//! of outstanding deadlines and the gap in time between polls. Removal is not
+//! O(1): it scans every entry in the one slot holding the deadline.
+
+use std::time::{Duration, Instant};
+
+const LEVELS: usize = 6;
+const SLOTS_PER_LEVEL: usize = 64;
+const SLOT_MASK: u64 = (SLOTS_PER_LEVEL as u64) - 1;
+// Level `n`'s slots each span `SLOTS_PER_LEVEL.pow(n)` ticks; a level-0 tick
+// is 1ms, so level 0 covers 64ms, level 1 covers ~4s, ..., level 5 covers
+// roughly 2 years, comfortably more than any deadline `winit` would be asked
+// to track.
+const TICK: Duration = Duration::from_millis(1);
+
+/// A handle returned by [`TimingWheel::insert`], used to [`TimingWheel::remove`]
+/// the deadline again before it fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerKey(u64);
+
+struct Location {
+    level: usize,
+    slot: usize,
+}
+
+struct Entry {
+    key: TimerKey,
+    deadline_tick: u64,
+}
+
+/// The entries cascaded or drained from a single `(level, slot)` bucket,
+/// together with the minimum deadline among them, kept up to date on every
+/// push/remove/drain so [`TimingWheel::next_deadline`] never has to look at
+/// the entries themselves.
+#[derive(Default)]
+struct Slot {
+    entries: Vec<Entry>,
+    min_deadline: Option<u64>,
+}
+
+impl Slot {
+    fn push(&mut self, entry: Entry) {
+        self.min_deadline = Some(match self.min_deadline {
+            Some(min) => min.min(entry.deadline_tick),
+            None => entry.deadline_tick,
+        });
+        self.entries.push(entry);
+    }
+
+    fn remove(&mut self, key: TimerKey) {
+        self.entries.retain(|entry| entry.key != key);
+        self.recompute_min();
+    }
+
+    fn drain(&mut self) -> Vec<Entry> {
+        self.min_deadline = None;
+        std::mem::take(&mut self.entries)
+    }
+
+    fn recompute_min(&mut self) {
+        self.min_deadline = self.entries.iter().map(|entry| entry.deadline_tick).min();
+    }
+}
+
+pub struct TimingWheel {
+    /// The instant `elapsed_tick == 0` corresponds to.
+    epoch: Instant,
+    /// How far, in ticks since `epoch`, this wheel has been advanced.
+    elapsed_tick: u64,
+    levels: [Vec<Slot>; LEVELS],
+    locations: std::collections::HashMap<u64, Location>,
+    next_id: u64,
+}
+
+impl TimingWheel {
+    pub fn new(epoch: Instant) -> Self {
+        TimingWheel {
+            epoch,
+            elapsed_tick: 0,
+            levels: std::array::from_fn(|_| (0..SLOTS_PER_LEVEL).map(|_| Slot::default()).collect()),
+            locations: std::collections::HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    fn tick_of(&self, instant: Instant) -> u64 {
+        if instant <= self.epoch {
+            0
+        } else {
+            ((instant - self.epoch).as_nanos() / TICK.as_nanos()) as u64
+        }
+    }
+
+    /// Which `(level, slot)` a deadline `ticks_remaining` ticks from now
+    /// belongs in: the lowest level whose span covers it.
+    fn locate(ticks_remaining: u64, deadline_tick: u64) -> Location {
+        let mut span = 1u64;
+        for level in 0..LEVELS {
+            let level_span = span * SLOTS_PER_LEVEL as u64;
+            if ticks_remaining < level_span || level == LEVELS - 1 {
+                let slot = ((deadline_tick / span) & SLOT_MASK) as usize;
+                return Location { level, slot };
+            }
+            span = level_span;
+        }
+        unreachable!()
+    }
+
+    /// Schedules a wakeup for `instant`, returning a key that can later be
+    /// passed to [`TimingWheel::remove`].
+    pub fn insert(&mut self, instant: Instant) -> TimerKey {
+        let deadline_tick = self.tick_of(instant);
+        let id = self.next_id;
+        self.next_id += 1;
+        let key = TimerKey(id);
+
+        let ticks_remaining = deadline_tick.saturating_sub(self.elapsed_tick);
+        let loc = Self::locate(ticks_remaining, deadline_tick);
+        self.levels[loc.level][loc.slot].push(Entry { key, deadline_tick });
+        self.locations.insert(id, loc);
+        key
+    }
+
+    /// Cancels a previously inserted deadline. A no-op if it already fired.
+    pub fn remove(&mut self, key: TimerKey) {
+        if let Some(loc) = self.locations.remove(&key.0) {
+            self.levels[loc.level][loc.slot].remove(key);
+        }
+    }
+
+    /// The earliest outstanding deadline, if any, as an [`Instant`].
+    ///
+    /// Only scans the fixed `LEVELS * SLOTS_PER_LEVEL` per-slot cached
+    /// minimums, not the outstanding entries themselves, so this stays O(1)
+    /// regardless of how many deadlines are tracked.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.levels
+            .iter()
+            .flat_map(|level| level.iter())
+            .filter_map(|slot| slot.min_deadline)
+            .min()
+            .map(|tick| self.epoch + Duration::from_millis(tick))
+    }
+
+    /// Every tick in `[old_elapsed, target]` that is a multiple of `span`,
+    /// most recent first, capped at `SLOTS_PER_LEVEL`.
+    ///
+    /// A level's slot index for a given tick is `(tick / span) & SLOT_MASK`,
+    /// which cycles through all `SLOTS_PER_LEVEL` values and repeats. So
+    /// once more than `SLOTS_PER_LEVEL` multiples of `span` have elapsed
+    /// since the last poll, every slot index has come up at least once, and
+    /// visiting the most recent occurrence of each is enough: a visit drains
+    /// the whole slot, so visiting an earlier occurrence of the same index
+    /// as well would only drain that slot a second time. That bounds the walk to
+    /// `SLOTS_PER_LEVEL` regardless of how big the gap between polls is.
+    fn crossed_boundaries(old_elapsed: u64, target: u64, span: u64) -> impl Iterator<Item = u64> {
+        let count = if old_elapsed == 0 {
+            target / span + 1
+        } else {
+            target / span - (old_elapsed - 1) / span
+        };
+        let count = count.min(SLOTS_PER_LEVEL as u64);
+        let last = (target / span) * span;
+        (0..count).map(move |i| last - i * span)
+    }
+
+    /// Advances the wheel to `now`, draining and returning every deadline
+    /// that is now due. Cascades higher levels down as their span elapses.
+    ///
+    /// Crossed boundaries are visited directly via `crossed_boundaries`
+    /// rather than by stepping through every tick in between, and a cascaded
+    /// entry is re-bucketed directly against `target_tick` rather than one
+    /// level at a time: `locate` alone already picks the right final level
+    /// and slot, including level 0 for anything that's actually due by
+    /// `target_tick`. This bounds the work per call to `LEVELS *
+    /// SLOTS_PER_LEVEL`, independent of the gap in ticks between polls.
+    pub fn poll_expired(&mut self, now: Instant) -> impl Iterator<Item = TimerKey> {
+        let old_elapsed = self.elapsed_tick;
+        let target_tick = self.tick_of(now).max(old_elapsed);
+        let mut expired = Vec::new();
+
+        let mut span = 1u64;
+        for level in 1..LEVELS {
+            let level_span = span * SLOTS_PER_LEVEL as u64;
+            for boundary_tick in Self::crossed_boundaries(old_elapsed, target_tick, level_span) {
+                let slot = ((boundary_tick / level_span) & SLOT_MASK) as usize;
+                for entry in self.levels[level][slot].drain() {
+                    let ticks_remaining = entry.deadline_tick.saturating_sub(target_tick);
+                    let loc = Self::locate(ticks_remaining, entry.deadline_tick);
+                    self.locations.insert(
+                        entry.key.0,
+                        Location {
+                            level: loc.level,
+                            slot: loc.slot,
+                        },
+                    );
+                    self.levels[loc.level][loc.slot].push(entry);
+                }
+            }
+            span = level_span;
+        }
+
+        // Level 0 only ever holds entries within `SLOTS_PER_LEVEL` ticks of
+        // firing (their own or a higher level's cascade above), so the same
+        // bounded walk reaches every one of them.
+        for tick in Self::crossed_boundaries(old_elapsed, target_tick, 1) {
+            let slot = (tick & SLOT_MASK) as usize;
+            let (due, not_due): (Vec<Entry>, Vec<Entry>) = self.levels[0][slot]
+                .drain()
+                .into_iter()
+                .partition(|entry| entry.deadline_tick <= target_tick);
+            for entry in not_due {
+                self.levels[0][slot].push(entry);
+            }
+            for entry in due {
+                self.locations.remove(&entry.key.0);
+                expired.push(entry.key);
+            }
+        }
+
+        self.elapsed_tick = target_tick;
+        expired.into_iter()
+    }
+}